@@ -18,15 +18,15 @@
 //! static DATA: DataLock<u32> = DataLock::new(0);
 //!
 //! fn main() {
-//!     if let Some(mut data) = DATA.try_lock() {
+//!     if let Ok(mut data) = DATA.try_lock() {
 //!         *data = 20;
 //!     }
 //!     // once the data goes ot of scope the lock will be released
-//!     if let Some(data) = DATA.try_lock() {
+//!     if let Ok(data) = DATA.try_lock() {
 //!         println!("data: {}", *data);
 //!
 //!         // another lock should fail inside this scope
-//!         assert!(DATA.try_lock().is_none());
+//!         assert!(DATA.try_lock().is_err());
 //!     }
 //! }
 //! ```
@@ -34,18 +34,133 @@
 //! The data might also be wrapped in an ``Arc<DataLock<T>>`` and shared between cores using clones
 //! of the ``Arc``.
 //!
+//! # Poisoning
+//! If a thread panics while holding the lock, ``DataLock`` gets marked as poisoned, following
+//! ``std::sync::Mutex``'s convention: any subsequent ``lock``/``try_lock`` still hands back the
+//! guard, but wrapped in a [`PoisonError`] so callers can decide whether the guarded data might be
+//! left in an inconsistent state. On bare-metal builds compiled with ``panic = "abort"`` there is
+//! no unwinding to observe, so the lock degenerates to never-poisoned.
+//!
+//! # Async acquisition
+//! Cooperative, bare-metal async executors cannot afford to let ``lock()`` spin on ``wfe`` and
+//! monopolize the core. [`DataLock::lock_async`] returns a [`Future`] that attempts ``try_lock`` on
+//! each poll and, if the lock is currently held, registers the task's waker instead so the executor
+//! can schedule other work until the lock is released.
+//!
+//! # Memory ordering
+//! Acquisition uses `Acquire`/`Relaxed` `compare_exchange` and release uses a `Release` store,
+//! instead of a full `SeqCst` swap on every attempt. This still establishes the happens-before
+//! relationship required for mutual exclusion: a releasing core's `Release` store synchronizes
+//! with the next acquiring core's successful `Acquire` CAS, so every write made while the lock was
+//! held is visible to the next holder. [`DataLock::try_lock`] uses the strong `compare_exchange`,
+//! since a single-shot API must not report the lock as held when it spuriously failed; the
+//! retrying loop inside [`DataLock::lock`] uses the cheaper `compare_exchange_weak` instead, since
+//! there a spurious failure just costs another spin. `lock()` additionally spins on a `Relaxed`
+//! load first, so waiting cores only attempt the more expensive CAS once the flag looks free,
+//! reducing cache-line ping-pong under contention.
+//!
+//! # Model-checking with `loom`
+//! Under `#[cfg(loom)]` this module swaps in `loom`'s atomics and thread primitives so the
+//! `loom_tests` below can exhaustively check the CAS-based locking against the memory model
+//! instead of trusting a single observed interleaving. `DataLock::new` loses its `const` under
+//! that cfg (`loom`'s atomics have no `const` constructor), which the `static DATA: DataLock<_> =
+//! DataLock::new(...)` pattern used throughout this file's doctests relies on - so loom runs must
+//! be scoped to the library target, skipping doctests entirely:
+//! ```text
+//! RUSTFLAGS="--cfg loom" cargo test --lib
+//! ```
 
 use core::cell::UnsafeCell;
+use core::future::Future;
 use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+#[cfg(not(loom))]
 use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(panic = "unwind")]
+extern crate std;
 
 /// An exclusive access lock around the given data
 #[repr(C, align(16))]
 pub struct DataLock<T> {
     locked: AtomicBool,
+    poisoned: AtomicBool,
+    waker: AtomicWaker,
     data: UnsafeCell<T>,
 }
 
+/// A minimal single-slot waker registration, storing at most one `Waker` so [`DataLock::lock_async`]
+/// can wake a single parked task when the lock is released. Concurrent ``register``/``wake`` calls
+/// are serialized via a small CAS spinlock; unlike the lock itself it raises no ``dmb``/``dsb``
+/// barriers.
+struct AtomicWaker {
+    locked: AtomicBool,
+    waker: UnsafeCell<Option<Waker>>,
+}
+
+impl AtomicWaker {
+    // loom's atomics have no `const` constructor, so this can only be `const` on the real path
+    #[cfg(not(loom))]
+    const fn new() -> Self {
+        AtomicWaker {
+            locked: AtomicBool::new(false),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    #[cfg(loom)]
+    fn new() -> Self {
+        AtomicWaker {
+            locked: AtomicBool::new(false),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            // loom does not treat a bare spin as yielding control, so it needs an explicit nudge
+            // to the scheduler or model exploration never terminates
+            #[cfg(loom)]
+            loom::thread::yield_now();
+        }
+
+        unsafe {
+            *self.waker.get() = Some(waker.clone());
+        }
+
+        self.locked.store(false, Ordering::Release);
+    }
+
+    fn wake(&self) {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            #[cfg(loom)]
+            loom::thread::yield_now();
+        }
+
+        let waker = unsafe { (*self.waker.get()).take() };
+
+        self.locked.store(false, Ordering::Release);
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+unsafe impl Sync for AtomicWaker {}
+
 /// Result of trying to access the data using ``try_lock`` or ``lock`` on the data lock. If the
 /// result goes out of scope the lock is released.
 #[derive(Debug)]
@@ -53,72 +168,243 @@ pub struct TryDataLock<'a, T> {
     _data: &'a DataLock<T>,
 }
 
+/// A type alias for the result returned by [`DataLock::lock`]. Carries the [`PoisonError`] wrapping
+/// the acquired guard if a previous holder panicked while the lock was held.
+pub type LockResult<Guard> = Result<Guard, PoisonError<Guard>>;
+
+/// A type alias for the result returned by [`DataLock::try_lock`].
+pub type TryLockResult<Guard> = Result<Guard, TryLockError<Guard>>;
+
+/// Indicates a previous holder of a ``DataLock`` panicked while the lock was held. Wraps the guard
+/// so the data can still be recovered via [`PoisonError::into_inner`].
+#[derive(Debug)]
+pub struct PoisonError<Guard> {
+    guard: Guard,
+}
+
+impl<Guard> PoisonError<Guard> {
+    fn new(guard: Guard) -> Self {
+        PoisonError { guard }
+    }
+
+    /// Consumes this error, returning the guard that was acquired despite the lock being poisoned.
+    pub fn into_inner(self) -> Guard {
+        self.guard
+    }
+
+    /// Returns a reference to the guard that was acquired despite the lock being poisoned.
+    pub fn get_ref(&self) -> &Guard {
+        &self.guard
+    }
+
+    /// Returns a mutable reference to the guard that was acquired despite the lock being poisoned.
+    pub fn get_mut(&mut self) -> &mut Guard {
+        &mut self.guard
+    }
+}
+
+/// An error returned from [`DataLock::try_lock`].
+#[derive(Debug)]
+pub enum TryLockError<Guard> {
+    /// The lock is poisoned, a previous holder panicked while the lock was held.
+    Poisoned(PoisonError<Guard>),
+    /// The lock is currently held elsewhere and could not be acquired at this time.
+    WouldBlock,
+}
+
 impl<T> DataLock<T> {
     /// Create a new data access guarding lock
+    #[cfg(not(loom))]
     pub const fn new(value: T) -> Self {
         DataLock {
             locked: AtomicBool::new(false),
+            poisoned: AtomicBool::new(false),
+            waker: AtomicWaker::new(),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Create a new data access guarding lock
+    ///
+    /// `loom`'s atomics have no `const` constructor, so under `#[cfg(loom)]` this is a regular
+    /// `fn` instead.
+    #[cfg(loom)]
+    pub fn new(value: T) -> Self {
+        DataLock {
+            locked: AtomicBool::new(false),
+            poisoned: AtomicBool::new(false),
+            waker: AtomicWaker::new(),
             data: UnsafeCell::new(value),
         }
     }
 
-    /// Try to lock the guarded data for mutual exclusive access. Returns ``None`` if the lock failes
-    /// or ``Some(TryDataLock)``. The actual data, the TryDataLock wraps could be conviniently accessed by
-    /// dereferencing it.
+    /// Try to lock the guarded data for mutual exclusive access. Returns ``Err(WouldBlock)`` if the
+    /// lock is currently held elsewhere, ``Err(Poisoned(_))`` if a previous holder panicked while
+    /// holding the lock, or ``Ok(TryDataLock)`` otherwise. The actual data, the TryDataLock wraps
+    /// could be conviniently accessed by dereferencing it.
     ///
     /// # Example
     /// ```
     /// # use ruspiro_lock::DataLock;
     /// static DATA: DataLock<u32> = DataLock::new(10);
     /// # fn main() {
-    ///     if let Some(data) = DATA.try_lock() {
+    ///     if let Ok(data) = DATA.try_lock() {
     ///         // do something with data
     ///     }
     /// # }
     /// ```
-    pub fn try_lock(&self) -> Option<TryDataLock<T>> {
-        // do the atomic operation to set the lock
-        if !self.locked.swap(true, Ordering::SeqCst) {
-            // has been false previously means we now have the lock
-
-            #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
-            unsafe {
-                // dmb required before allow access to the protected resource, see:
-                // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
-                llvm_asm!("dmb sy");
-            }
-
-            Some(TryDataLock { _data: self })
+    pub fn try_lock(&self) -> TryLockResult<TryDataLock<'_, T>> {
+        // a single-shot attempt must use the strong `compare_exchange`: the weak form is allowed
+        // to fail spuriously on LL/SC targets even while the lock is free, which would make
+        // `try_lock` lie about the lock being held
+        if self
+            .locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+        {
+            self.finish_acquire()
         } else {
             // we couldn't set the lock
-            None
+            Err(TryLockError::WouldBlock)
+        }
+    }
+
+    // shared tail of a successful acquisition: raise the barrier and check for poisoning. Takes
+    // the place of duplicating this between `try_lock` and the retrying loop in `lock`.
+    fn finish_acquire(&self) -> TryLockResult<TryDataLock<'_, T>> {
+        // has been false previously means we now have the lock
+
+        #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), not(loom)))]
+        unsafe {
+            // dmb required before allow access to the protected resource, see:
+            // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
+            llvm_asm!("dmb sy");
+        }
+
+        let guard = TryDataLock { _data: self };
+        if self.is_poisoned() {
+            Err(TryLockError::Poisoned(PoisonError::new(guard)))
+        } else {
+            Ok(guard)
         }
     }
 
     /// Lock the guarded data for mutual exclusive access. This blocks until the data could be
-    /// successfully locked. The locked data will be returned as ``TryDataLock``. Simply derefrencing
-    /// this allows access to the contained data value.
+    /// successfully locked. The locked data will be returned as ``TryDataLock``, wrapped in a
+    /// [`PoisonError`] if a previous holder panicked while holding the lock. Simply derefrencing
+    /// the guard allows access to the contained data value.
     ///
     /// # Example
     /// ```
     /// # use ruspiro_lock::DataLock;
     /// static DATA: DataLock<u32> = DataLock::new(10);
     /// # fn main() {
-    ///     let mut data = DATA.lock();
+    ///     let mut data = DATA.lock().unwrap();
     ///     // do something with data
     ///     *data = 15;
     ///
     /// # }
     /// ```
-    pub fn lock(&self) -> TryDataLock<T> {
+    pub fn lock(&self) -> LockResult<TryDataLock<'_, T>> {
         loop {
-            if let Some(data) = self.try_lock() {
-                return data;
+            // a relaxed pre-check spin so waiters only attempt the contended compare_exchange
+            // once the flag looks free, instead of hammering the cache line with a failing RMW
+            // on every iteration
+            while self.locked.load(Ordering::Relaxed) {
+                // to save energy and cpu consumption we can wait for an event beeing raised that
+                // indicates that the semaphore value has likely beeing changed
+                #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), not(loom)))]
+                unsafe {
+                    llvm_asm!("wfe");
+                }
+                // on hosts without the ARM wfe/sev event mechanism, hint to the CPU that this is a
+                // busy-wait spin rather than burning full-throttle cycles
+                #[cfg(all(not(any(target_arch = "arm", target_arch = "aarch64")), not(loom)))]
+                core::hint::spin_loop();
+                // loom does not model `spin_loop` as yielding control, so explicitly hand off to
+                // the scheduler or its model exploration treats this as non-terminating
+                #[cfg(loom)]
+                loom::thread::yield_now();
+            }
+
+            // the blocking loop is the one place a spurious failure merely costs another spin of
+            // the outer `loop`, so the cheaper weak CAS (which may fail even when the lock is
+            // free on LL/SC targets) is the right tradeoff here, unlike in `try_lock`
+            if self
+                .locked
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                match self.finish_acquire() {
+                    Ok(guard) => return Ok(guard),
+                    Err(TryLockError::Poisoned(err)) => return Err(err),
+                    Err(TryLockError::WouldBlock) => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Returns whether this lock is poisoned, i.e. a previous holder of the lock panicked while
+    /// the data was locked.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::SeqCst)
+    }
+
+    /// Clears the poisoned state of this lock, so that future ``lock``/``try_lock`` calls succeed
+    /// without a [`PoisonError`] again. Use this only after having verified the guarded data is
+    /// still in a consistent state.
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::SeqCst);
+    }
+
+    /// Lock the guarded data for mutual exclusive access without blocking the core. Returns a
+    /// [`Future`] that attempts ``try_lock`` on each poll and, while the lock is held elsewhere,
+    /// registers the polling task's waker so it gets woken up once the lock is released.
+    ///
+    /// Note the waker registration is a single slot: if more than one task is parked in
+    /// `lock_async` at the same time, registering a new waker overwrites whichever one was stored
+    /// before, and the guard's `Drop` only wakes one task. Concurrent async waiters beyond one are
+    /// therefore not guaranteed to be woken and might stall until something else happens to poll
+    /// them again; prefer a single `lock_async` waiter per `DataLock`, or the blocking `lock()`/
+    /// `try_lock()` for multi-waiter scenarios.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::DataLock;
+    /// static DATA: DataLock<u32> = DataLock::new(10);
+    /// # async fn example() {
+    ///     let mut data = DATA.lock_async().await.unwrap();
+    ///     *data = 15;
+    /// # }
+    /// ```
+    pub fn lock_async(&self) -> LockFuture<'_, T> {
+        LockFuture { lock: self }
+    }
+}
+
+/// The [`Future`] returned from [`DataLock::lock_async`].
+pub struct LockFuture<'a, T> {
+    lock: &'a DataLock<T>,
+}
+
+impl<'a, T> Future for LockFuture<'a, T> {
+    type Output = LockResult<TryDataLock<'a, T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.lock.try_lock() {
+            Ok(guard) => Poll::Ready(Ok(guard)),
+            Err(TryLockError::Poisoned(err)) => Poll::Ready(Err(err)),
+            Err(TryLockError::WouldBlock) => {
+                self.lock.waker.register(cx.waker());
+
+                // the lock might have been released between the failed try_lock above and
+                // registering the waker, so check once more before actually parking the task
+                match self.lock.try_lock() {
+                    Ok(guard) => Poll::Ready(Ok(guard)),
+                    Err(TryLockError::Poisoned(err)) => Poll::Ready(Err(err)),
+                    Err(TryLockError::WouldBlock) => Poll::Pending,
+                }
             }
-            // to save energy and cpu consumption we can wait for an event beeing raised that indicates that the 
-            // semaphore value has likely beeing changed
-            #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
-            unsafe { llvm_asm!("wfe"); }
         }
     }
 }
@@ -136,20 +422,35 @@ impl<T> core::fmt::Debug for DataLock<T>
 // when the TryDataLock is dropped release the owning lock
 impl<T> Drop for TryDataLock<'_, T> {
     fn drop(&mut self) {
-        self._data.locked.swap(false, Ordering::SeqCst);
+        // if we are unwinding due to a panic while holding the lock, poison it so the next locker
+        // is made aware the guarded data might be in an inconsistent state. On `panic = "abort"`
+        // builds there is no unwinding to observe and the lock simply never gets poisoned.
+        #[cfg(panic = "unwind")]
+        {
+            if std::thread::panicking() {
+                self._data.poisoned.store(true, Ordering::SeqCst);
+            }
+        }
+
+        // release ordering publishes everything written while the lock was held to whichever
+        // core's `Acquire` CAS in `try_lock` or `lock` next observes this store
+        self._data.locked.store(false, Ordering::Release);
 
-        #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+        #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), not(loom)))]
         unsafe {
             // dmb required before allow access to the protected resource, see:
             // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
             llvm_asm!("dmb sy");
-            // also raise a signal to indicate the semaphore has been changed (this trigger all WFE's to continue 
+            // also raise a signal to indicate the semaphore has been changed (this trigger all WFE's to continue
             // processing) but do data syncronisation barrier upfront to ensure any data updates has been finished
             llvm_asm!(
                 "dsb sy
                  sev"
             );
         }
+
+        // also wake up a task that might be parked in a `lock_async` future waiting for this lock
+        self._data.waker.wake();
     }
 }
 
@@ -173,3 +474,255 @@ impl<T> DerefMut for TryDataLock<'_, T> {
 
 unsafe impl<T> Sync for DataLock<T> {}
 unsafe impl<T> Send for DataLock<T> {}
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::*;
+    use loom::sync::Arc;
+    use loom::thread;
+
+    // model check that at most one TryDataLock ever observes the guarded data mutably, letting
+    // loom exhaustively permute the possible thread interleavings and atomic orderings. Loom only
+    // switches threads at its own atomics, so the guard count is kept in a loom atomic.
+    #[test]
+    fn mutual_exclusion() {
+        loom::model(|| {
+            let lock = Arc::new(DataLock::new(0u32));
+            let holders = Arc::new(loom::sync::atomic::AtomicUsize::new(0));
+
+            let lock2 = Arc::clone(&lock);
+            let holders2 = Arc::clone(&holders);
+            let t1 = thread::spawn(move || {
+                if let Ok(mut data) = lock.try_lock() {
+                    assert_eq!(holders.fetch_add(1, Ordering::SeqCst), 0);
+                    let before = *data;
+                    *data = before + 1;
+                    assert_eq!(*data, before + 1);
+                    holders.fetch_sub(1, Ordering::SeqCst);
+                }
+            });
+            let t2 = thread::spawn(move || {
+                if let Ok(mut data) = lock2.try_lock() {
+                    assert_eq!(holders2.fetch_add(1, Ordering::SeqCst), 0);
+                    let before = *data;
+                    *data = before + 1;
+                    assert_eq!(*data, before + 1);
+                    holders2.fetch_sub(1, Ordering::SeqCst);
+                }
+            });
+
+            t1.join().unwrap();
+            t2.join().unwrap();
+        });
+    }
+
+    // the blocking lock() must eventually be acquired by every thread, never deadlocking.
+    #[test]
+    fn blocking_lock_makes_progress() {
+        loom::model(|| {
+            let lock = Arc::new(DataLock::new(0u32));
+
+            let lock1 = Arc::clone(&lock);
+            let lock2 = Arc::clone(&lock);
+            let t1 = thread::spawn(move || {
+                let mut data = lock1.lock().unwrap();
+                *data += 1;
+            });
+            let t2 = thread::spawn(move || {
+                let mut data = lock2.lock().unwrap();
+                *data += 1;
+            });
+
+            t1.join().unwrap();
+            t2.join().unwrap();
+
+            assert_eq!(*lock.lock().unwrap(), 2);
+        });
+    }
+
+    // documents that the Acquire/Release pair still establishes the happens-before relationship
+    // required for mutual exclusion: a write made by the releasing core must be visible to
+    // whichever core next successfully acquires the lock, even though neither side uses SeqCst.
+    // Joining a thread before reading would establish its own happens-before and let the
+    // assertion pass even with `Relaxed` orderings - exactly the bug this test needs to be able
+    // to catch - so neither thread here knows in advance whether it goes first or second; each
+    // calls `lock()` exactly once and checks a `turn` counter guarded by the very same lock to
+    // find out. Whichever thread observes `turn == 1` acquired strictly after the other (lock
+    // mutual exclusion guarantees that ordering regardless of memory ordering), and must then see
+    // the `value` the first thread wrote only if the lock's Acquire/Release actually publishes it.
+    // Loom only models visibility for its own atomics, so `value` is a loom atomic accessed with
+    // `Relaxed` only, leaving the lock as the sole source of the happens-before edge.
+    #[test]
+    fn release_acquire_happens_before() {
+        loom::model(|| {
+            let lock = Arc::new(DataLock::new(0u32)); // turn
+            let value = Arc::new(loom::sync::atomic::AtomicU32::new(0));
+
+            let lock1 = Arc::clone(&lock);
+            let value1 = Arc::clone(&value);
+            let t1 = thread::spawn(move || {
+                let mut turn = lock1.lock().unwrap();
+                if *turn == 0 {
+                    value1.store(42, Ordering::Relaxed);
+                    *turn = 1;
+                } else {
+                    assert_eq!(value1.load(Ordering::Relaxed), 42);
+                }
+            });
+
+            let lock2 = Arc::clone(&lock);
+            let value2 = Arc::clone(&value);
+            let t2 = thread::spawn(move || {
+                let mut turn = lock2.lock().unwrap();
+                if *turn == 0 {
+                    value2.store(42, Ordering::Relaxed);
+                    *turn = 1;
+                } else {
+                    assert_eq!(value2.load(Ordering::Relaxed), 42);
+                }
+            });
+
+            t1.join().unwrap();
+            t2.join().unwrap();
+        });
+    }
+}
+
+// host-only tests for the async wake path, run with a plain `cargo test` rather than under loom
+// since they don't depend on the exhaustive interleaving exploration loom provides. Gated off
+// under `loom` because `DataLock::new` is non-const there (see the module doc above), which
+// these tests' `static` locks rely on.
+#[cfg(all(test, not(loom)))]
+mod async_tests {
+    use super::*;
+    use core::task::{RawWaker, RawWakerVTable};
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    #[derive(Default)]
+    struct FlagWaker {
+        woken: core::sync::atomic::AtomicBool,
+    }
+
+    impl Wake for FlagWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.woken.store(true, core::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    // a `Pending` `LockFuture` must actually be woken once the guard holding the lock drops,
+    // not just be re-pollable by luck.
+    #[test]
+    fn lock_async_wakes_parked_task_after_guard_drop() {
+        let lock = DataLock::new(0u32);
+        let guard = lock.lock().unwrap();
+
+        let flag = Arc::new(FlagWaker::default());
+        let waker = Waker::from(Arc::clone(&flag));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut fut = lock.lock_async();
+        assert!(matches!(
+            Pin::new(&mut fut).poll(&mut cx),
+            Poll::Pending
+        ));
+        assert!(!flag.woken.load(core::sync::atomic::Ordering::SeqCst));
+
+        drop(guard);
+        assert!(flag.woken.load(core::sync::atomic::Ordering::SeqCst));
+
+        let polled = Pin::new(&mut fut).poll(&mut cx);
+        match polled {
+            Poll::Ready(Ok(_)) => {}
+            other => panic!("expected the woken future to resolve, got {:?}", other),
+        }
+    }
+
+    // exercises the double-check performed right after `register`: a release that lands in the
+    // narrow window between the future's first failed `try_lock` and the waker registration must
+    // still be observed by that same `poll` call, instead of parking the task to wait for a wake
+    // that will never come. Simulated by flipping the lock's own `locked` flag directly (this
+    // test lives in the same module, so it can reach the private field) from the test waker's
+    // `clone` callback, which `AtomicWaker::register` invokes right before the post-register
+    // re-check. Going through a real guard's `Drop` here instead would call back into
+    // `AtomicWaker::wake` while `register` still holds its internal spinlock, deadlocking.
+    #[test]
+    fn lock_async_second_check_catches_release_racing_registration() {
+        static LOCK: DataLock<u32> = DataLock::new(0);
+
+        LOCK.locked.store(true, core::sync::atomic::Ordering::SeqCst);
+
+        unsafe fn clone(data: *const ()) -> RawWaker {
+            LOCK.locked.store(false, core::sync::atomic::Ordering::Release);
+            RawWaker::new(data, &VTABLE)
+        }
+        unsafe fn no_op(_data: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+
+        let raw = RawWaker::new(core::ptr::null(), &VTABLE);
+        let waker = unsafe { Waker::from_raw(raw) };
+        let mut cx = Context::from_waker(&waker);
+
+        let mut fut = LOCK.lock_async();
+        let polled = Pin::new(&mut fut).poll(&mut cx);
+        match polled {
+            Poll::Ready(Ok(guard)) => drop(guard),
+            other => panic!(
+                "expected the post-register re-check to observe the release, got {:?}",
+                other
+            ),
+        }
+    }
+}
+
+#[cfg(all(test, panic = "unwind", not(loom)))]
+mod poison_tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn panic_while_held_poisons_the_lock() {
+        let lock = DataLock::new(0u32);
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let mut data = lock.lock().unwrap();
+            *data = 42;
+            panic!("simulated panic while holding the lock");
+        }));
+        assert!(result.is_err());
+        assert!(lock.is_poisoned());
+
+        match lock.try_lock() {
+            Err(TryLockError::Poisoned(err)) => assert_eq!(*err.into_inner(), 42),
+            other => panic!("expected try_lock to report poisoning, got {:?}", other),
+        }
+
+        let locked = lock.lock();
+        match locked {
+            Err(err) => assert_eq!(*err.into_inner(), 42),
+            Ok(_) => panic!("expected lock() to report poisoning too"),
+        }
+    }
+
+    #[test]
+    fn clear_poison_resets_the_lock() {
+        let lock = DataLock::new(0u32);
+
+        let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let _data = lock.lock().unwrap();
+            panic!("simulated panic while holding the lock");
+        }));
+        assert!(lock.is_poisoned());
+
+        lock.clear_poison();
+        assert!(!lock.is_poisoned());
+
+        let data = lock.lock().unwrap();
+        assert_eq!(*data, 0);
+    }
+}
@@ -0,0 +1,25 @@
+/***************************************************************************************************
+ * Copyright (c) 2019 by the authors
+ *
+ * Author: André Borrmann
+ * License: Apache License 2.0
+ **************************************************************************************************/
+#![no_std]
+
+//! # Ruspiro Lock
+//!
+//! Provides simple, cross core locking and data guarding primitives intended to be used in a bare
+//! metal environment running on the Raspberry Pi. As there is no operating system available the
+//! locks implemented here do not rely on any OS provided synchronization facilities but solely on
+//! atomic operations and the ARM ``wfe``/``sev`` event mechanism to put a waiting core to sleep
+//! until the lock could likely be acquired.
+//!
+
+mod datalock;
+pub use datalock::*;
+
+mod rwdatalock;
+pub use rwdatalock::*;
+
+mod semaphore;
+pub use semaphore::*;
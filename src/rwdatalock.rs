@@ -0,0 +1,284 @@
+/***************************************************************************************************
+ * Copyright (c) 2019 by the authors
+ *
+ * Author: André Borrmann
+ * License: Apache License 2.0
+ **************************************************************************************************/
+
+//! # Reader/Writer Data Lock
+//!
+//! While ``DataLock`` only ever allows a single exclusive accessor, many bare-metal data structures
+//! (device register shadows, configuration tables) are read far more often than they are written.
+//! ``RwDataLock<T>`` permits any number of concurrent readers *or* a single writer across cores,
+//! mirroring the API of ``std::sync::RwLock``.
+//!
+//! # Example
+//! ```
+//! use ruspiro_lock::RwDataLock;
+//!
+//! static DATA: RwDataLock<u32> = RwDataLock::new(0);
+//!
+//! fn main() {
+//!     if let Some(mut data) = DATA.try_write() {
+//!         *data = 20;
+//!     }
+//!     // once the writer goes out of scope the lock will be released
+//!     if let Some(data) = DATA.try_read() {
+//!         println!("data: {}", *data);
+//!
+//!         // further readers are allowed while this one is still active
+//!         assert!(DATA.try_read().is_some());
+//!         // but a writer has to wait until all readers are gone
+//!         assert!(DATA.try_write().is_none());
+//!     }
+//! }
+//! ```
+//!
+
+use core::cell::UnsafeCell;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// state bit that marks an active writer, the remaining bits count active readers
+const WRITER: usize = 1 << (core::mem::size_of::<usize>() * 8 - 1);
+
+/// A reader/writer access lock around the given data
+#[repr(C, align(16))]
+pub struct RwDataLock<T> {
+    state: AtomicUsize,
+    data: UnsafeCell<T>,
+}
+
+/// Shared read access to the data guarded by a ``RwDataLock``. As soon as this guard goes out of
+/// scope the read lock is released.
+#[derive(Debug)]
+pub struct RwDataLockReadGuard<'a, T> {
+    _data: &'a RwDataLock<T>,
+}
+
+/// Exclusive write access to the data guarded by a ``RwDataLock``. As soon as this guard goes out
+/// of scope the write lock is released.
+#[derive(Debug)]
+pub struct RwDataLockWriteGuard<'a, T> {
+    _data: &'a RwDataLock<T>,
+}
+
+impl<T> RwDataLock<T> {
+    /// Create a new reader/writer access guarding lock
+    pub const fn new(value: T) -> Self {
+        RwDataLock {
+            state: AtomicUsize::new(0),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Try to acquire shared read access to the guarded data. Returns ``None`` if a writer is
+    /// currently active or ``Some(RwDataLockReadGuard)`` otherwise. Any number of readers might
+    /// hold the lock concurrently.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::RwDataLock;
+    /// static DATA: RwDataLock<u32> = RwDataLock::new(10);
+    /// # fn main() {
+    ///     if let Some(data) = DATA.try_read() {
+    ///         // do something with data
+    ///     }
+    /// # }
+    /// ```
+    pub fn try_read(&self) -> Option<RwDataLockReadGuard<'_, T>> {
+        let mut current = self.state.load(Ordering::SeqCst);
+        loop {
+            if current & WRITER != 0 {
+                return None;
+            }
+
+            match self.state.compare_exchange(
+                current,
+                current + 1,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => {
+                    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+                    unsafe {
+                        // dmb required before allow access to the protected resource, see:
+                        // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
+                        llvm_asm!("dmb sy");
+                    }
+
+                    return Some(RwDataLockReadGuard { _data: self });
+                }
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Acquire shared read access to the guarded data. This blocks until no writer is active
+    /// anymore. The locked data will be returned as ``RwDataLockReadGuard``.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::RwDataLock;
+    /// static DATA: RwDataLock<u32> = RwDataLock::new(10);
+    /// # fn main() {
+    ///     let data = DATA.read();
+    ///     // do something with data
+    /// # }
+    /// ```
+    pub fn read(&self) -> RwDataLockReadGuard<'_, T> {
+        loop {
+            if let Some(data) = self.try_read() {
+                return data;
+            }
+            // to save energy and cpu consumption we can wait for an event beeing raised that
+            // indicates that the semaphore value has likely beeing changed
+            #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+            unsafe {
+                llvm_asm!("wfe");
+            }
+        }
+    }
+
+    /// Try to acquire exclusive write access to the guarded data. Returns ``None`` if the lock is
+    /// currently held by a reader or another writer, or ``Some(RwDataLockWriteGuard)`` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::RwDataLock;
+    /// static DATA: RwDataLock<u32> = RwDataLock::new(10);
+    /// # fn main() {
+    ///     if let Some(mut data) = DATA.try_write() {
+    ///         *data = 15;
+    ///     }
+    /// # }
+    /// ```
+    pub fn try_write(&self) -> Option<RwDataLockWriteGuard<'_, T>> {
+        if self
+            .state
+            .compare_exchange(0, WRITER, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+            unsafe {
+                // dmb required before allow access to the protected resource, see:
+                // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
+                llvm_asm!("dmb sy");
+            }
+
+            Some(RwDataLockWriteGuard { _data: self })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire exclusive write access to the guarded data. This blocks until neither a reader nor
+    /// another writer hold the lock anymore. The locked data will be returned as
+    /// ``RwDataLockWriteGuard``.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::RwDataLock;
+    /// static DATA: RwDataLock<u32> = RwDataLock::new(10);
+    /// # fn main() {
+    ///     let mut data = DATA.write();
+    ///     *data = 15;
+    /// # }
+    /// ```
+    pub fn write(&self) -> RwDataLockWriteGuard<'_, T> {
+        loop {
+            if let Some(data) = self.try_write() {
+                return data;
+            }
+            // to save energy and cpu consumption we can wait for an event beeing raised that
+            // indicates that the semaphore value has likely beeing changed
+            #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+            unsafe {
+                llvm_asm!("wfe");
+            }
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for RwDataLock<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RwDataLock")
+            .field("Value", unsafe { &*self.data.get() })
+            .finish()
+    }
+}
+
+// releasing a read guard just decrements the reader count
+impl<T> Drop for RwDataLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        self._data.state.fetch_sub(1, Ordering::SeqCst);
+
+        #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+        unsafe {
+            // dmb required before allow access to the protected resource, see:
+            // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
+            llvm_asm!("dmb sy");
+            // also raise a signal to indicate the semaphore has been changed (this trigger all WFE's to continue
+            // processing) but do data syncronisation barrier upfront to ensure any data updates has been finished
+            llvm_asm!(
+                "dsb sy
+                 sev"
+            );
+        }
+    }
+}
+
+// releasing a write guard clears the writer bit
+impl<T> Drop for RwDataLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        self._data.state.store(0, Ordering::SeqCst);
+
+        #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+        unsafe {
+            // dmb required before allow access to the protected resource, see:
+            // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
+            llvm_asm!("dmb sy");
+            // also raise a signal to indicate the semaphore has been changed (this trigger all WFE's to continue
+            // processing) but do data syncronisation barrier upfront to ensure any data updates has been finished
+            llvm_asm!(
+                "dsb sy
+                 sev"
+            );
+        }
+    }
+}
+
+// dereferencing the value contained in the RwDataLockReadGuard
+// this is ok as a RwDataLockReadGuard does only exist while the writer bit is cleared, so the data
+// is never mutated concurrently while one or more readers hold a reference to it.
+impl<T> Deref for RwDataLockReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self._data.data.get() }
+    }
+}
+
+// dereferencing the value contained in the RwDataLockWriteGuard
+// this is ok as only one RwDataLockWriteGuard could ever exist for one specific RwDataLock at a
+// time and no reader could be active while the writer bit is set, which makes it safe to return a
+// mutable reference.
+impl<T> Deref for RwDataLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self._data.data.get() }
+    }
+}
+
+impl<T> DerefMut for RwDataLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self._data.data.get() }
+    }
+}
+
+unsafe impl<T> Sync for RwDataLock<T> {}
+unsafe impl<T> Send for RwDataLock<T> {}
@@ -0,0 +1,145 @@
+/***************************************************************************************************
+ * Copyright (c) 2019 by the authors
+ *
+ * Author: André Borrmann
+ * License: Apache License 2.0
+ **************************************************************************************************/
+
+//! # Semaphore
+//!
+//! A counting semaphore usable to bound concurrent access to a given number of resources (e.g. DMA
+//! channels, mailbox slots) across cores. While ``DataLock`` only ever allows a single exclusive
+//! accessor, a ``Semaphore`` allows up to ``N`` accessors at the same time.
+//!
+//! # Example
+//! ```
+//! use ruspiro_lock::Semaphore;
+//!
+//! static DMA_CHANNELS: Semaphore = Semaphore::new(4);
+//!
+//! fn main() {
+//!     if DMA_CHANNELS.try_down() {
+//!         // use one of the 4 available DMA channels
+//!
+//!         // once done release it back to the semaphore
+//!         DMA_CHANNELS.up();
+//!     }
+//! }
+//! ```
+//!
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// A counting semaphore guarding access to a limited number of resources across cores
+#[repr(C, align(16))]
+pub struct Semaphore {
+    count: AtomicU32,
+}
+
+impl Semaphore {
+    /// Create a new semaphore allowing up to ``count`` concurrent accessors
+    pub const fn new(count: u32) -> Self {
+        Semaphore {
+            count: AtomicU32::new(count),
+        }
+    }
+
+    /// Try to acquire one of the available resources. Returns ``true`` if a resource could be
+    /// acquired, ``false`` if none were available.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::Semaphore;
+    /// static SEMA: Semaphore = Semaphore::new(1);
+    /// # fn main() {
+    ///     if SEMA.try_down() {
+    ///         // do something with the acquired resource
+    ///     }
+    /// # }
+    /// ```
+    pub fn try_down(&self) -> bool {
+        let mut current = self.count.load(Ordering::SeqCst);
+        loop {
+            if current == 0 {
+                return false;
+            }
+
+            match self.count.compare_exchange(
+                current,
+                current - 1,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => {
+                    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+                    unsafe {
+                        // dmb required before allow access to the protected resource, see:
+                        // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
+                        llvm_asm!("dmb sy");
+                    }
+
+                    return true;
+                }
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Acquire one of the available resources. This blocks until a resource becomes available.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::Semaphore;
+    /// static SEMA: Semaphore = Semaphore::new(1);
+    /// # fn main() {
+    ///     SEMA.down();
+    ///     // do something with the acquired resource
+    /// # }
+    /// ```
+    pub fn down(&self) {
+        loop {
+            if self.try_down() {
+                return;
+            }
+            // to save energy and cpu consumption we can wait for an event beeing raised that
+            // indicates that the semaphore value has likely beeing changed
+            #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+            unsafe {
+                llvm_asm!("wfe");
+            }
+        }
+    }
+
+    /// Release a previously acquired resource back to the semaphore, waking up any core that is
+    /// currently waiting inside ``down``.
+    ///
+    /// # Example
+    /// ```
+    /// # use ruspiro_lock::Semaphore;
+    /// static SEMA: Semaphore = Semaphore::new(1);
+    /// # fn main() {
+    ///     SEMA.down();
+    ///     // do something with the acquired resource
+    ///     SEMA.up();
+    /// # }
+    /// ```
+    pub fn up(&self) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+        unsafe {
+            // dmb required before allow access to the protected resource, see:
+            // http://infocenter.arm.com/help/topic/com.arm.doc.dht0008a/DHT0008A_arm_synchronization_primitives.pdf
+            llvm_asm!("dmb sy");
+            // also raise a signal to indicate the semaphore has been changed (this trigger all WFE's to continue
+            // processing) but do data syncronisation barrier upfront to ensure any data updates has been finished
+            llvm_asm!(
+                "dsb sy
+                 sev"
+            );
+        }
+    }
+}
+
+unsafe impl Sync for Semaphore {}
+unsafe impl Send for Semaphore {}